@@ -1,7 +1,187 @@
+use std::sync::Mutex;
+
+use serde_json::json;
 use tauri::{
-    menu::{Menu, MenuItem, PredefinedMenuItem, Submenu},
-    Manager,
+    menu::{CheckMenuItem, Menu, MenuItem, PredefinedMenuItem, Submenu},
+    tray::{TrayIconBuilder, TrayIconEvent},
+    Emitter, Manager,
 };
+use tauri_plugin_store::StoreExt;
+
+const ZOOM_STEP: f64 = 0.1;
+const ZOOM_MIN: f64 = 0.5;
+const ZOOM_MAX: f64 = 3.0;
+const ZOOM_DEFAULT: f64 = 1.0;
+
+const SETTINGS_STORE: &str = "settings.json";
+const SETTING_ZOOM: &str = "zoom";
+const SETTING_MENU_VISIBLE: &str = "menu_visible";
+const SETTING_NOTIFICATIONS_ENABLED: &str = "notifications_enabled";
+
+/// Handles to long-lived UI resources that need to be reachable from
+/// event callbacks (menu clicks, tray clicks, commands).
+struct AppState {
+    tray: tauri::tray::TrayIcon,
+    menu: Menu<tauri::Wry>,
+    zoom: Mutex<f64>,
+    // Only the macOS `toggle_menu_visibility` arm needs to track this; on
+    // Windows/Linux the window itself is the source of truth via
+    // `is_menu_visible()`.
+    #[cfg(target_os = "macos")]
+    menu_visible: Mutex<bool>,
+    notifications_enabled: Mutex<bool>,
+}
+
+/// Persists a single setting into the `settings.json` store, saving it to
+/// disk immediately so it survives a crash, not just a clean exit.
+fn persist_setting(app: &tauri::AppHandle, key: &str, value: serde_json::Value) {
+    if let Ok(store) = app.store(SETTINGS_STORE) {
+        store.set(key, value);
+        let _ = store.save();
+    }
+}
+
+/// Builds the app's menu bar, following each platform's own conventions
+/// rather than reusing a single layout everywhere.
+fn build_menu(app: &tauri::AppHandle, notifications_enabled: bool) -> tauri::Result<Menu<tauri::Wry>> {
+    let preferences_item =
+        MenuItem::with_id(app, "menu_preferences", "Preferences…", true, None::<&str>)?;
+    let new_window_item =
+        MenuItem::with_id(app, "menu_new_window", "New Window", true, None::<&str>)?;
+    let notifications_item = CheckMenuItem::with_id(
+        app,
+        "menu_notifications_enabled",
+        "Enable Notifications",
+        true,
+        notifications_enabled,
+        None::<&str>,
+    )?;
+
+    let edit_menu = Submenu::with_items(
+        app,
+        "Edit",
+        true,
+        &[
+            &PredefinedMenuItem::undo(app, Some("Undo"))?,
+            &PredefinedMenuItem::redo(app, Some("Redo"))?,
+            &PredefinedMenuItem::separator(app)?,
+            &PredefinedMenuItem::cut(app, Some("Cut"))?,
+            &PredefinedMenuItem::copy(app, Some("Copy"))?,
+            &PredefinedMenuItem::paste(app, Some("Paste"))?,
+            &PredefinedMenuItem::select_all(app, Some("Select All"))?,
+        ],
+    )?;
+
+    let toggle_sidebar_item = MenuItem::with_id(
+        app,
+        "view_toggle_sidebar",
+        "Toggle Sidebar",
+        true,
+        None::<&str>,
+    )?;
+    let zoom_in_item = MenuItem::with_id(
+        app,
+        "view_zoom_in",
+        "Zoom In",
+        true,
+        Some("CmdOrCtrl+Plus"),
+    )?;
+    let zoom_out_item = MenuItem::with_id(
+        app,
+        "view_zoom_out",
+        "Zoom Out",
+        true,
+        Some("CmdOrCtrl+-"),
+    )?;
+    let reset_zoom_item = MenuItem::with_id(
+        app,
+        "view_reset_zoom",
+        "Reset Zoom",
+        true,
+        Some("CmdOrCtrl+0"),
+    )?;
+    let toggle_menu_bar_item = MenuItem::with_id(
+        app,
+        "view_toggle_menu_bar",
+        "Toggle Menu Bar",
+        true,
+        Some("CmdOrCtrl+Alt+M"),
+    )?;
+    let view_menu = Submenu::with_items(
+        app,
+        "View",
+        true,
+        &[
+            &toggle_sidebar_item,
+            &PredefinedMenuItem::separator(app)?,
+            &zoom_in_item,
+            &zoom_out_item,
+            &reset_zoom_item,
+            &PredefinedMenuItem::separator(app)?,
+            &toggle_menu_bar_item,
+        ],
+    )?;
+
+    #[cfg(target_os = "macos")]
+    {
+        let app_menu = Submenu::with_items(
+            app,
+            "Flack",
+            true,
+            &[
+                &PredefinedMenuItem::about(app, Some("About Flack"), None)?,
+                &PredefinedMenuItem::separator(app)?,
+                &preferences_item,
+                &notifications_item,
+                &PredefinedMenuItem::separator(app)?,
+                &PredefinedMenuItem::services(app, Some("Services"))?,
+                &PredefinedMenuItem::separator(app)?,
+                &PredefinedMenuItem::hide(app, Some("Hide Flack"))?,
+                &PredefinedMenuItem::hide_others(app, Some("Hide Others"))?,
+                &PredefinedMenuItem::show_all(app, Some("Show All"))?,
+                &PredefinedMenuItem::separator(app)?,
+                &new_window_item,
+                &PredefinedMenuItem::separator(app)?,
+                &PredefinedMenuItem::quit(app, Some("Quit Flack"))?,
+            ],
+        )?;
+
+        let window_menu = Submenu::with_items(
+            app,
+            "Window",
+            true,
+            &[
+                &PredefinedMenuItem::minimize(app, Some("Minimize"))?,
+                &PredefinedMenuItem::maximize(app, Some("Zoom"))?,
+                &PredefinedMenuItem::separator(app)?,
+                &PredefinedMenuItem::close_window(app, Some("Close"))?,
+            ],
+        )?;
+
+        Menu::with_items(app, &[&app_menu, &edit_menu, &view_menu, &window_menu])
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        let file_menu = Submenu::with_items(
+            app,
+            "File",
+            true,
+            &[
+                &preferences_item,
+                &notifications_item,
+                &PredefinedMenuItem::separator(app)?,
+                &new_window_item,
+                &PredefinedMenuItem::separator(app)?,
+                &PredefinedMenuItem::close_window(app, Some("Close Window"))?,
+                &PredefinedMenuItem::separator(app)?,
+                &PredefinedMenuItem::quit(app, Some("Quit"))?,
+            ],
+        )?;
+
+        Menu::with_items(app, &[&file_menu, &edit_menu, &view_menu])
+    }
+}
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
@@ -10,51 +190,253 @@ pub fn run() {
         .plugin(tauri_plugin_window_state::Builder::new().build())
         .plugin(tauri_plugin_store::Builder::new().build())
         .plugin(tauri_plugin_notification::init())
+        .invoke_handler(tauri::generate_handler![toggle_menu])
         .setup(|app| {
-            // Build app menu
-            let app_menu = Submenu::with_items(
-                app,
-                "Flack",
-                true,
-                &[
-                    &PredefinedMenuItem::about(app, Some("About Flack"), None)?,
-                    &PredefinedMenuItem::separator(app)?,
-                    &PredefinedMenuItem::quit(app, Some("Quit Flack"))?,
-                ],
-            )?;
+            let store = app.store(SETTINGS_STORE)?;
+            let initial_zoom = store
+                .get(SETTING_ZOOM)
+                .and_then(|v| v.as_f64())
+                .map(|z| z.clamp(ZOOM_MIN, ZOOM_MAX))
+                .unwrap_or(ZOOM_DEFAULT);
+            let initial_menu_visible = store
+                .get(SETTING_MENU_VISIBLE)
+                .and_then(|v| v.as_bool())
+                .unwrap_or(true);
+            let initial_notifications_enabled = store
+                .get(SETTING_NOTIFICATIONS_ENABLED)
+                .and_then(|v| v.as_bool())
+                .unwrap_or(true);
 
-            let edit_menu = Submenu::with_items(
-                app,
-                "Edit",
-                true,
-                &[
-                    &PredefinedMenuItem::undo(app, Some("Undo"))?,
-                    &PredefinedMenuItem::redo(app, Some("Redo"))?,
-                    &PredefinedMenuItem::separator(app)?,
-                    &PredefinedMenuItem::cut(app, Some("Cut"))?,
-                    &PredefinedMenuItem::copy(app, Some("Copy"))?,
-                    &PredefinedMenuItem::paste(app, Some("Paste"))?,
-                    &PredefinedMenuItem::select_all(app, Some("Select All"))?,
-                ],
-            )?;
+            let menu = build_menu(app.handle(), initial_notifications_enabled)?;
+            app.set_menu(menu.clone())?;
 
-            let window_menu = Submenu::with_items(
+            if let Some(window) = app.get_webview_window("main") {
+                if let Err(err) = window.set_zoom(initial_zoom) {
+                    eprintln!("failed to restore webview zoom to {initial_zoom}: {err}");
+                }
+            }
+
+            // Custom menu items don't do anything on their own; forward clicks to the
+            // frontend so JS can react to native menu actions.
+            app.on_menu_event(|app, event| {
+                match event.id().as_ref() {
+                    "view_zoom_in" => apply_zoom_delta(app, ZOOM_STEP),
+                    "view_zoom_out" => apply_zoom_delta(app, -ZOOM_STEP),
+                    "view_reset_zoom" => set_zoom(app, ZOOM_DEFAULT),
+                    "view_toggle_menu_bar" => {
+                        let _ = toggle_menu_visibility(app);
+                    }
+                    "menu_notifications_enabled" => toggle_notifications_enabled(app),
+                    id => {
+                        let topic = match id {
+                            "menu_preferences" => Some("menu://preferences"),
+                            "menu_new_window" => Some("menu://new-window"),
+                            "view_toggle_sidebar" => Some("menu://view/toggle-sidebar"),
+                            _ => None,
+                        };
+                        if let Some(topic) = topic {
+                            let _ = app.emit(topic, ());
+                        }
+                    }
+                }
+            });
+
+            // Tray icon: gives Flack a presence even when the main window is hidden.
+            let tray_show = MenuItem::with_id(app, "tray_show", "Show Flack", true, None::<&str>)?;
+            let tray_hide = MenuItem::with_id(app, "tray_hide", "Hide", true, None::<&str>)?;
+            let tray_quit = MenuItem::with_id(app, "tray_quit", "Quit", true, None::<&str>)?;
+            let tray_menu = Menu::with_items(
                 app,
-                "Window",
-                true,
                 &[
-                    &PredefinedMenuItem::minimize(app, Some("Minimize"))?,
-                    &PredefinedMenuItem::maximize(app, Some("Zoom"))?,
+                    &tray_show,
+                    &tray_hide,
                     &PredefinedMenuItem::separator(app)?,
-                    &PredefinedMenuItem::close_window(app, Some("Close"))?,
+                    &tray_quit,
                 ],
             )?;
 
-            let menu = Menu::with_items(app, &[&app_menu, &edit_menu, &window_menu])?;
-            app.set_menu(menu)?;
+            let tray = TrayIconBuilder::new()
+                .menu(&tray_menu)
+                .show_menu_on_left_click(false)
+                .on_menu_event(|app, event| match event.id().as_ref() {
+                    "tray_show" => {
+                        if let Some(window) = app.get_webview_window("main") {
+                            let _ = window.show();
+                            let _ = window.set_focus();
+                        }
+                    }
+                    "tray_hide" => {
+                        if let Some(window) = app.get_webview_window("main") {
+                            let _ = window.hide();
+                        }
+                    }
+                    "tray_quit" => app.exit(0),
+                    _ => {}
+                })
+                .on_tray_icon_event(|tray, event| {
+                    if let TrayIconEvent::Click {
+                        button: tauri::tray::MouseButton::Left,
+                        button_state: tauri::tray::MouseButtonState::Up,
+                        ..
+                    } = event
+                    {
+                        let app = tray.app_handle();
+                        if let Some(window) = app.get_webview_window("main") {
+                            let visible = window.is_visible().unwrap_or(false);
+                            if visible {
+                                let _ = window.hide();
+                            } else {
+                                let _ = window.show();
+                                let _ = window.set_focus();
+                            }
+                        }
+                    }
+                })
+                .build(app)?;
+
+            app.manage(AppState {
+                tray,
+                menu,
+                zoom: Mutex::new(initial_zoom),
+                #[cfg(target_os = "macos")]
+                menu_visible: Mutex::new(true),
+                notifications_enabled: Mutex::new(initial_notifications_enabled),
+            });
+
+            if !initial_menu_visible {
+                let _ = toggle_menu_visibility(app.handle());
+            }
 
             Ok(())
         })
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
+
+/// Adjusts the focused webview's zoom by `delta`, clamped to
+/// [`ZOOM_MIN`, `ZOOM_MAX`], and persists the new factor in app state.
+///
+/// Requires the `webview:allow-set-webview-zoom` capability to be granted
+/// to the main window.
+fn apply_zoom_delta(app: &tauri::AppHandle, delta: f64) {
+    let state = app.state::<AppState>();
+    let mut zoom = state.zoom.lock().unwrap();
+    *zoom = (*zoom + delta).clamp(ZOOM_MIN, ZOOM_MAX);
+    if let Some(window) = app.get_webview_window("main") {
+        if let Err(err) = window.set_zoom(*zoom) {
+            eprintln!("failed to set webview zoom to {}: {err}", *zoom);
+        }
+    }
+    persist_setting(app, SETTING_ZOOM, json!(*zoom));
+}
+
+/// Sets the focused webview's zoom to an absolute factor, clamped to
+/// [`ZOOM_MIN`, `ZOOM_MAX`], and persists it in app state.
+fn set_zoom(app: &tauri::AppHandle, level: f64) {
+    let state = app.state::<AppState>();
+    let mut zoom = state.zoom.lock().unwrap();
+    *zoom = level.clamp(ZOOM_MIN, ZOOM_MAX);
+    if let Some(window) = app.get_webview_window("main") {
+        if let Err(err) = window.set_zoom(*zoom) {
+            eprintln!("failed to set webview zoom to {}: {err}", *zoom);
+        }
+    }
+    persist_setting(app, SETTING_ZOOM, json!(*zoom));
+}
+
+/// Flips the "Enable Notifications" preference and persists it so it
+/// survives a restart.
+fn toggle_notifications_enabled(app: &tauri::AppHandle) {
+    let state = app.state::<AppState>();
+    let mut enabled = state.notifications_enabled.lock().unwrap();
+    *enabled = !*enabled;
+    persist_setting(app, SETTING_NOTIFICATIONS_ENABLED, json!(*enabled));
+
+    // Menu click events don't update the native checkbox glyph on their
+    // own; reach back into the stored menu (the item lives one level down,
+    // inside the app/File submenu) and flip it to match.
+    if let Ok(top_items) = state.menu.items() {
+        for top_item in top_items {
+            let Some(submenu) = top_item.as_submenu() else {
+                continue;
+            };
+            let Ok(items) = submenu.items() else {
+                continue;
+            };
+            for item in items {
+                if let Some(check_item) = item.as_check_menuitem() {
+                    if check_item.id().as_ref() == "menu_notifications_enabled" {
+                        let _ = check_item.set_checked(*enabled);
+                    }
+                }
+            }
+        }
+    }
+
+    let tooltip = if *enabled {
+        "Flack"
+    } else {
+        "Flack (notifications off)"
+    };
+    let _ = state.tray.set_tooltip(Some(tooltip));
+    let _ = app.emit("menu://notifications-enabled", *enabled);
+}
+
+/// Collapses/expands the native menu bar so users can reclaim screen space
+/// on platforms where the menu lives in the window rather than on a global
+/// menu bar.
+#[tauri::command]
+fn toggle_menu(app: tauri::AppHandle) -> Result<(), String> {
+    toggle_menu_visibility(&app).map_err(|e| e.to_string())
+}
+
+/// On Windows/Linux the menu bar belongs to the window, so it can be
+/// hidden and shown outright.
+#[cfg(not(target_os = "macos"))]
+fn toggle_menu_visibility(app: &tauri::AppHandle) -> tauri::Result<()> {
+    let mut visible = true;
+    if let Some(window) = app.get_webview_window("main") {
+        if window.is_menu_visible()? {
+            window.hide_menu()?;
+            visible = false;
+        } else {
+            window.show_menu()?;
+        }
+    }
+    persist_setting(app, SETTING_MENU_VISIBLE, json!(visible));
+    Ok(())
+}
+
+/// On macOS the menu is app-global and can't be hidden, so approximate a
+/// toggle by enabling/disabling the top-level submenus through the handle
+/// stored in state. The submenu holding `view_toggle_menu_bar` itself is
+/// left enabled, since disabling it would also block the accelerator that
+/// undoes the toggle, locking the menu bar off for good.
+#[cfg(target_os = "macos")]
+fn toggle_menu_visibility(app: &tauri::AppHandle) -> tauri::Result<()> {
+    let state = app.state::<AppState>();
+    let mut visible = state.menu_visible.lock().unwrap();
+    *visible = !*visible;
+    for item in state.menu.items()? {
+        let Some(submenu) = item.as_submenu() else {
+            continue;
+        };
+        if submenu_contains_id(submenu, "view_toggle_menu_bar")? {
+            continue;
+        }
+        submenu.set_enabled(*visible)?;
+    }
+    persist_setting(app, SETTING_MENU_VISIBLE, json!(*visible));
+    Ok(())
+}
+
+/// Whether `submenu` directly contains an item with the given id.
+#[cfg(target_os = "macos")]
+fn submenu_contains_id(submenu: &Submenu<tauri::Wry>, id: &str) -> tauri::Result<bool> {
+    for item in submenu.items()? {
+        if item.id().as_ref() == id {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}